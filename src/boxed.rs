@@ -0,0 +1,114 @@
+use crate::{Error, Service};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + Sync + 'static>>;
+
+trait DynService<Request, Response> {
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>>;
+    fn call(&mut self, req: Request) -> BoxFuture<Response>;
+}
+
+impl<Request, S> DynService<Request, S::Response> for S
+where
+    S: Service<Request>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Service::poll_ready(self, cx)
+    }
+
+    fn call(&mut self, req: Request) -> BoxFuture<S::Response> {
+        Service::call(self, req)
+    }
+}
+
+/// A type-erased, `Send + Sync` [`Service`], produced by
+/// [`ServiceExt::boxed`].
+pub struct BoxService<Request, Response> {
+    inner: Box<dyn DynService<Request, Response> + Send + Sync>,
+}
+
+impl<Request, Response> BoxService<Request, Response> {
+    /// Erase the concrete type of `inner`.
+    pub fn new<S>(inner: S) -> Self
+    where
+        S: Service<Request, Response = Response> + Send + Sync + 'static,
+    {
+        BoxService {
+            inner: Box::new(inner),
+        }
+    }
+}
+
+impl<Request, Response> Service<Request> for BoxService<Request, Response> {
+    type Response = Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        self.inner.call(req)
+    }
+}
+
+/// A type-erased [`Service`] that drops the `Send + Sync` bound on the
+/// wrapped service, for single-threaded callers whose service can't satisfy
+/// it.
+pub struct UnsyncBoxService<Request, Response> {
+    inner: Box<dyn DynService<Request, Response>>,
+}
+
+impl<Request, Response> UnsyncBoxService<Request, Response> {
+    /// Erase the concrete type of `inner`.
+    pub fn new<S>(inner: S) -> Self
+    where
+        S: Service<Request, Response = Response> + 'static,
+    {
+        UnsyncBoxService {
+            inner: Box::new(inner),
+        }
+    }
+}
+
+impl<Request, Response> Service<Request> for UnsyncBoxService<Request, Response> {
+    type Response = Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        self.inner.call(req)
+    }
+}
+
+/// Extension methods for erasing a [`Service`]'s concrete type.
+pub trait ServiceExt<Request>: Service<Request> {
+    /// Box this service, erasing its type. Requires the service to be
+    /// `Send + Sync`; see [`boxed_unsync`](ServiceExt::boxed_unsync) if it
+    /// isn't.
+    fn boxed(self) -> BoxService<Request, Self::Response>
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        BoxService::new(self)
+    }
+
+    /// Box this service, erasing its type without requiring `Send + Sync`.
+    fn boxed_unsync(self) -> UnsyncBoxService<Request, Self::Response>
+    where
+        Self: Sized + 'static,
+    {
+        UnsyncBoxService::new(self)
+    }
+}
+
+impl<Request, S: Service<Request>> ServiceExt<Request> for S {}
@@ -0,0 +1,104 @@
+use crate::{Error, Service};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio_util::sync::PollSender;
+
+type Envelope<Req, Res> = (Req, oneshot::Sender<Result<Res, Error>>);
+
+/// A [`Service`] middleware that moves `call` onto a spawned worker task,
+/// decoupling callers from the underlying service and enforcing
+/// backpressure through `poll_ready` exactly as documented on the trait.
+///
+/// This makes streaming endpoints safe to share across many concurrent
+/// callers without head-of-line stalls: callers wait on `poll_ready` for
+/// buffer space rather than on the inner service's own readiness.
+pub struct Buffer<Req, Res> {
+    tx: PollSender<Envelope<Req, Res>>,
+}
+
+impl<Req, Res> Clone for Buffer<Req, Res> {
+    fn clone(&self) -> Self {
+        Buffer {
+            tx: self.tx.clone(),
+        }
+    }
+}
+
+impl<Req, Res> Buffer<Req, Res>
+where
+    Req: Send + 'static,
+    Res: Send + Sync + 'static,
+{
+    /// Wrap `service`, spawning a worker task that drives it and accepting
+    /// up to `bound` requests waiting for the worker before `poll_ready`
+    /// reports the buffer full.
+    pub fn new<S>(mut service: S, bound: usize) -> Self
+    where
+        S: Service<Req, Response = Res> + Send + 'static,
+    {
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<Envelope<Req, Res>>(bound);
+
+        tokio::spawn(async move {
+            while let Some((req, reply)) = rx.recv().await {
+                let ready = std::future::poll_fn(|cx| service.poll_ready(cx)).await;
+                let fut = match ready {
+                    Ok(()) => service.call(req),
+                    Err(e) => {
+                        let _ = reply.send(Err(e));
+                        continue;
+                    }
+                };
+                // Resolve each call on its own task so a slow response doesn't
+                // hold up the requests queued behind it. The caller may have
+                // dropped the future awaiting this reply; that's not our problem.
+                tokio::spawn(async move {
+                    let _ = reply.send(fut.await);
+                });
+            }
+        });
+
+        Buffer {
+            tx: PollSender::new(tx),
+        }
+    }
+}
+
+impl<Req, Res> Service<Req> for Buffer<Req, Res>
+where
+    Req: Send + 'static,
+    Res: Send + Sync + 'static,
+{
+    type Response = Res;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.tx.poll_reserve(cx).map_err(|_| Error::Capacity)
+    }
+
+    fn call(
+        &mut self,
+        req: Req,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        // `call` is permitted without a preceding `poll_ready`, and
+        // `PollSender::send_item` panics unless a reservation was just made
+        // on this exact handle, so reserve here too — it's a no-op if
+        // `poll_ready` already reserved a slot.
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        match self.tx.poll_reserve(&mut cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(_)) | Poll::Pending => {
+                return Box::pin(async { Err(Error::Capacity) });
+            }
+        }
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        let sent = self.tx.send_item((req, reply_tx));
+
+        Box::pin(async move {
+            sent.map_err(|_| Error::Capacity)?;
+            reply_rx.await.map_err(|_| Error::Capacity)?
+        })
+    }
+}
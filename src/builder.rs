@@ -0,0 +1,95 @@
+use crate::rate_limit::RateLimitLayer;
+use crate::timeout::TimeoutLayer;
+use crate::Layer;
+use std::time::Duration;
+
+/// A [`Layer`] that returns the service unchanged; the default, empty state
+/// of a [`ServiceBuilder`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Identity;
+
+impl<S> Layer<S> for Identity {
+    type Service = S;
+
+    fn layer(&self, inner: S) -> S {
+        inner
+    }
+}
+
+/// Composes two layers, applying `Inner` first and wrapping its result with
+/// `Outer`.
+#[derive(Clone, Copy, Debug)]
+pub struct Stack<Inner, Outer> {
+    inner: Inner,
+    outer: Outer,
+}
+
+impl<S, Inner, Outer> Layer<S> for Stack<Inner, Outer>
+where
+    Inner: Layer<S>,
+    Outer: Layer<Inner::Service>,
+{
+    type Service = Outer::Service;
+
+    fn layer(&self, service: S) -> Self::Service {
+        let inner = self.inner.layer(service);
+        self.outer.layer(inner)
+    }
+}
+
+/// Fluently stacks [`Layer`]s into a single composed layer, mirroring
+/// `tower::ServiceBuilder`. Each call adds a layer inside the layers added
+/// so far, so the *first* layer added is the outermost and sees each
+/// request first:
+///
+/// ```ignore
+/// let service = ServiceBuilder::new()
+///     .rate_limit(100, Duration::from_secs(1))
+///     .timeout(Duration::from_secs(5))
+///     .service(inner);
+/// ```
+///
+/// produces `RateLimit<Timeout<inner>>`: requests are rate-limited before
+/// the timeout clock (and the inner service) ever sees them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ServiceBuilder<L = Identity> {
+    layer: L,
+}
+
+impl ServiceBuilder<Identity> {
+    /// Start an empty builder.
+    pub fn new() -> Self {
+        ServiceBuilder { layer: Identity }
+    }
+}
+
+impl<L> ServiceBuilder<L> {
+    /// Add an arbitrary [`Layer`] to the stack, making it the new innermost
+    /// layer — it sees each request after every layer added before it.
+    pub fn layer<T>(self, layer: T) -> ServiceBuilder<Stack<T, L>> {
+        ServiceBuilder {
+            layer: Stack {
+                inner: layer,
+                outer: self.layer,
+            },
+        }
+    }
+
+    /// Add a [`RateLimit`](crate::RateLimit) layer to the stack.
+    pub fn rate_limit(self, limit: u64, period: Duration) -> ServiceBuilder<Stack<RateLimitLayer, L>> {
+        self.layer(RateLimitLayer::new(limit, period))
+    }
+
+    /// Add a [`Timeout`](crate::Timeout) layer to the stack.
+    pub fn timeout(self, duration: Duration) -> ServiceBuilder<Stack<TimeoutLayer, L>> {
+        self.layer(TimeoutLayer::new(duration))
+    }
+
+    /// Apply the stacked layers to `inner`, producing the composed service.
+    pub fn service<S>(self, inner: S) -> L::Service
+    where
+        L: Layer<S>,
+    {
+        self.layer.layer(inner)
+    }
+}
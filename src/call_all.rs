@@ -0,0 +1,178 @@
+use crate::{Error, Service};
+use futures::stream::{FuturesOrdered, FuturesUnordered, Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Matches the boxed future returned by [`Service::call`] (`Send + Sync`,
+/// not `futures::future::BoxFuture`'s `Send`-only boxed future).
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + Sync + 'static>>;
+
+/// Drive `service` over `requests`, yielding each response in the order its
+/// request arrived.
+///
+/// This turns an inbound request stream, such as the ones the
+/// `streaming_unary`/`streaming_streaming` endpoints take, into an outbound
+/// response stream.
+/// Call [`CallAll::unordered`] to yield responses as soon as they complete
+/// instead.
+pub fn call_all<S, Req>(
+    service: S,
+    requests: impl Stream<Item = Req> + Send + 'static,
+) -> CallAll<S, Req>
+where
+    S: Service<Req>,
+{
+    CallAll::new(service, requests)
+}
+
+/// A stream of responses produced by driving a [`Service`] over a stream of
+/// requests, preserving request order. See [`call_all`].
+pub struct CallAll<S, Req>
+where
+    S: Service<Req>,
+{
+    service: S,
+    requests: Pin<Box<dyn Stream<Item = Req> + Send>>,
+    queue: FuturesOrdered<BoxFuture<Result<S::Response, Error>>>,
+    eof: bool,
+    done: bool,
+}
+
+impl<S, Req> CallAll<S, Req>
+where
+    S: Service<Req>,
+{
+    pub fn new(service: S, requests: impl Stream<Item = Req> + Send + 'static) -> Self {
+        CallAll {
+            service,
+            requests: Box::pin(requests),
+            queue: FuturesOrdered::new(),
+            eof: false,
+            done: false,
+        }
+    }
+
+    /// Drop request ordering, yielding responses as soon as they complete.
+    pub fn unordered(self) -> CallAllUnordered<S, Req> {
+        CallAllUnordered {
+            service: self.service,
+            requests: self.requests,
+            queue: FuturesUnordered::new(),
+            eof: self.eof,
+            done: self.done,
+        }
+    }
+}
+
+impl<S, Req> Stream for CallAll<S, Req>
+where
+    S: Service<Req> + Unpin,
+    Req: Send + 'static,
+    S::Response: Send + Sync + 'static,
+{
+    type Item = Result<S::Response, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        poll_call_all(
+            &mut this.service,
+            this.requests.as_mut(),
+            &mut this.queue,
+            &mut this.eof,
+            &mut this.done,
+            cx,
+        )
+    }
+}
+
+/// The `unordered` counterpart to [`CallAll`], produced by
+/// [`CallAll::unordered`].
+pub struct CallAllUnordered<S, Req>
+where
+    S: Service<Req>,
+{
+    service: S,
+    requests: Pin<Box<dyn Stream<Item = Req> + Send>>,
+    queue: FuturesUnordered<BoxFuture<Result<S::Response, Error>>>,
+    eof: bool,
+    done: bool,
+}
+
+impl<S, Req> Stream for CallAllUnordered<S, Req>
+where
+    S: Service<Req> + Unpin,
+    Req: Send + 'static,
+    S::Response: Send + Sync + 'static,
+{
+    type Item = Result<S::Response, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        poll_call_all(
+            &mut this.service,
+            this.requests.as_mut(),
+            &mut this.queue,
+            &mut this.eof,
+            &mut this.done,
+            cx,
+        )
+    }
+}
+
+/// Shared poll logic for the ordered and unordered queues: never calls
+/// `service.call` until `poll_ready` has reported ready since the last
+/// dispatch, keeps draining in-flight responses after the request stream
+/// ends before yielding `None`, and — once any response (or `poll_ready`)
+/// resolves to `Err` — surfaces that one error and then ends the stream,
+/// without dispatching further requests or yielding whatever else is still
+/// in flight.
+fn poll_call_all<S, Req, Q>(
+    service: &mut S,
+    requests: Pin<&mut (dyn Stream<Item = Req> + Send)>,
+    queue: &mut Q,
+    eof: &mut bool,
+    done: &mut bool,
+    cx: &mut Context<'_>,
+) -> Poll<Option<Result<S::Response, Error>>>
+where
+    S: Service<Req>,
+    S::Response: Send + Sync + 'static,
+    Q: Stream<Item = Result<S::Response, Error>>
+        + Unpin
+        + Extend<BoxFuture<Result<S::Response, Error>>>,
+{
+    if *done {
+        return Poll::Ready(None);
+    }
+
+    let mut requests = requests;
+
+    if !*eof {
+        match service.poll_ready(cx) {
+            Poll::Ready(Ok(())) => match requests.as_mut().poll_next(cx) {
+                Poll::Ready(Some(req)) => queue.extend(std::iter::once(service.call(req))),
+                Poll::Ready(None) => *eof = true,
+                Poll::Pending => {}
+            },
+            Poll::Ready(Err(e)) => {
+                *eof = true;
+                *done = true;
+                return Poll::Ready(Some(Err(e)));
+            }
+            Poll::Pending => {}
+        }
+    }
+
+    match Pin::new(queue).poll_next(cx) {
+        Poll::Ready(Some(item)) => {
+            if item.is_err() {
+                *eof = true;
+                *done = true;
+            }
+            Poll::Ready(Some(item))
+        }
+        Poll::Ready(None) if *eof => Poll::Ready(None),
+        Poll::Ready(None) | Poll::Pending => Poll::Pending,
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// A boxed standard error, used as an escape hatch for inner service errors
+/// that don't need their own variant.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// The error type produced by [`Service`](crate::Service) implementations in
+/// this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying transport failed.
+    Transport(BoxError),
+
+    /// The service is at capacity per the [`poll_ready`](crate::Service::poll_ready)
+    /// contract and cannot accept the request right now.
+    Capacity,
+
+    /// The request did not complete before its deadline.
+    Timeout,
+
+    /// Any other error produced by an inner service.
+    Inner(BoxError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+            Error::Capacity => write!(f, "service at capacity"),
+            Error::Timeout => write!(f, "request timed out"),
+            Error::Inner(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) | Error::Inner(e) => Some(e.as_ref()),
+            Error::Capacity | Error::Timeout => None,
+        }
+    }
+}
+
+impl From<tonic::Status> for Error {
+    fn from(status: tonic::Status) -> Self {
+        Error::Inner(Box::new(status))
+    }
+}
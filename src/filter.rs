@@ -0,0 +1,90 @@
+use crate::{Error, Service};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Checks a request before it is allowed to reach the inner service of a
+/// [`Filter`].
+///
+/// A predicate may validate, transform, or reject the request — for
+/// example, rejecting a `unary_unary::Request` that fails a schema check.
+pub trait Predicate<Request> {
+    /// The future returned by `check`.
+    type Future: Future<Output = Result<Request, Error>> + Send + Sync + 'static;
+
+    /// Validate or transform `request`, resolving to `Err` if it should not
+    /// reach the inner service.
+    fn check(&mut self, request: Request) -> Self::Future;
+}
+
+impl<F, Request, Fut> Predicate<Request> for F
+where
+    F: FnMut(Request) -> Fut,
+    Fut: Future<Output = Result<Request, Error>> + Send + Sync + 'static,
+{
+    type Future = Fut;
+
+    fn check(&mut self, request: Request) -> Self::Future {
+        self(request)
+    }
+}
+
+/// A [`Service`] middleware that gates requests on an async [`Predicate`]
+/// before they reach the inner service, mirroring classic Tower filtering
+/// middleware.
+///
+/// The predicate runs off-task, ahead of the inner service's own `call`: the
+/// future `call` returns first awaits the predicate and only then dispatches
+/// to `inner`, never touching it if the predicate rejects the request.
+///
+/// `inner` must be `Clone + Sync`: `call` can't hold `&mut self` across the
+/// predicate's await point and still dispatch to `inner` afterwards, so each
+/// `call` clones `inner` and calls the clone once the predicate resolves —
+/// and the clone is held across that await inside the returned future, which
+/// is itself required to be `Sync`.
+///
+/// That combination rules out wrapping most of this crate's own stateful
+/// middleware: `Buffer` isn't `Sync` (its `PollSender` holds a `Send`-only
+/// boxed future), and deriving `Clone` on something like `RateLimit` would
+/// type-check but silently break it, since each clone's `remaining`/
+/// `window_start` mutations are invisible to the others. `Filter` is best
+/// suited to services that are already cheap, stateless, and `Clone + Sync`
+/// — a thin wrapper around shared, interior-mutable state (e.g. an `Arc`) —
+/// rather than to the other middleware in this module.
+pub struct Filter<S, P> {
+    inner: S,
+    predicate: P,
+}
+
+impl<S, P> Filter<S, P> {
+    /// Wrap `inner`, gating requests through `predicate` first.
+    pub fn new(inner: S, predicate: P) -> Self {
+        Filter { inner, predicate }
+    }
+}
+
+impl<S, P, Request> Service<Request> for Filter<S, P>
+where
+    S: Service<Request> + Clone + Send + Sync + 'static,
+    S::Response: Send + Sync + 'static,
+    P: Predicate<Request>,
+{
+    type Response = S::Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        let check = self.predicate.check(req);
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            let req = check.await?;
+            inner.call(req).await
+        })
+    }
+}
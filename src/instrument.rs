@@ -0,0 +1,120 @@
+use crate::{Error, Service};
+use futures::stream::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use tracing::{Instrument as _, Level, Span};
+
+/// A [`Service`] middleware that opens a `tracing` span for each `call`,
+/// recording the endpoint kind, a generated request id, and timing — the
+/// data a tokio-console-style task/instrument collector aggregates.
+///
+/// The span is attached to the returned future via
+/// [`Instrument`](tracing::Instrument), so it
+/// follows the future across executor threads rather than being entered
+/// synchronously in `call`.
+pub struct Instrumented<S> {
+    inner: S,
+    endpoint: &'static str,
+    level: Level,
+    next_id: AtomicU64,
+}
+
+impl<S> Instrumented<S> {
+    /// Wrap `inner`, tagging each call's span with `endpoint` and emitting it
+    /// at `level`.
+    pub fn new(inner: S, endpoint: &'static str, level: Level) -> Self {
+        Instrumented {
+            inner,
+            endpoint,
+            level,
+            next_id: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<S, Request> Service<Request> for Instrumented<S>
+where
+    S: Service<Request> + Send + 'static,
+    S::Response: Send + Sync + 'static,
+    Request: Send + 'static,
+{
+    type Response = S::Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        let request_id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let endpoint = self.endpoint;
+        let span = match self.level {
+            Level::TRACE => tracing::trace_span!("rpc", endpoint, request_id),
+            Level::DEBUG => tracing::debug_span!("rpc", endpoint, request_id),
+            Level::INFO => tracing::info_span!("rpc", endpoint, request_id),
+            Level::WARN => tracing::warn_span!("rpc", endpoint, request_id),
+            Level::ERROR => tracing::error_span!("rpc", endpoint, request_id),
+        };
+        let start = std::time::Instant::now();
+        let fut = self.inner.call(req);
+
+        Box::pin(
+            async move {
+                let result = fut.await;
+                tracing::event!(
+                    Level::TRACE,
+                    elapsed_us = start.elapsed().as_micros() as u64,
+                    ok = result.is_ok(),
+                    "rpc.complete"
+                );
+                result
+            }
+            .instrument(span),
+        )
+    }
+}
+
+/// Wraps a stream so each `poll_next` runs inside `span` and emits an event
+/// per item, so an external collector can aggregate poll counts and
+/// durations for a streaming call. Pair with [`Instrumented::call`]'s span
+/// to correlate a streaming endpoint's items with its originating call.
+pub struct InstrumentedStream<St> {
+    inner: St,
+    span: Span,
+}
+
+impl<St> InstrumentedStream<St> {
+    /// Wrap `inner`, running every poll inside `span`.
+    pub fn new(inner: St, span: Span) -> Self {
+        InstrumentedStream { inner, span }
+    }
+}
+
+impl<St> Stream for InstrumentedStream<St>
+where
+    St: Stream + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let _enter = this.span.enter();
+        let start = std::time::Instant::now();
+        let poll = Pin::new(&mut this.inner).poll_next(cx);
+
+        if let Poll::Ready(item) = &poll {
+            tracing::event!(
+                Level::TRACE,
+                elapsed_us = start.elapsed().as_micros() as u64,
+                has_item = item.is_some(),
+                "rpc.poll"
+            );
+        }
+
+        poll
+    }
+}
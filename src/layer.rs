@@ -0,0 +1,12 @@
+/// Decorates a [`Service`](crate::Service), producing a new one.
+///
+/// Implementing `Layer` rather than wrapping a service directly lets the
+/// same middleware be stacked through a [`ServiceBuilder`](crate::ServiceBuilder)
+/// without naming the wrapped service's type by hand.
+pub trait Layer<S> {
+    /// The wrapped service produced by this layer.
+    type Service;
+
+    /// Wrap `inner` with this layer's behavior.
+    fn layer(&self, inner: S) -> Self::Service;
+}
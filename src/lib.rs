@@ -4,9 +4,28 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+mod boxed;
+mod buffer;
+mod builder;
+mod call_all;
+mod error;
+mod filter;
+mod instrument;
+mod layer;
 mod proto;
+mod rate_limit;
+mod timeout;
 
-pub struct Error;
+pub use boxed::{BoxService, ServiceExt, UnsyncBoxService};
+pub use buffer::Buffer;
+pub use builder::{Identity, ServiceBuilder, Stack};
+pub use call_all::{call_all, CallAll, CallAllUnordered};
+pub use error::{BoxError, Error};
+pub use instrument::{Instrumented, InstrumentedStream};
+pub use filter::{Filter, Predicate};
+pub use layer::Layer;
+pub use rate_limit::{RateLimit, RateLimitLayer};
+pub use timeout::{Timeout, TimeoutLayer};
 
 pub trait Service<Request> {
     /// Responses given by the service
@@ -40,17 +59,19 @@ pub trait Service<Request> {
 
 impl<Request, T> Service<Request> for T
 where
-    T: tower::Service<Request, Error = Error>,
+    T: tower::Service<Request>,
+    T::Error: Into<Error>,
     T::Future: Future<Output = Result<T::Response, T::Error>> + Send + Sync + 'static,
 {
     type Response = T::Response;
     fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
-        self.poll_ready(cx)
+        tower::Service::poll_ready(self, cx).map_err(Into::into)
     }
 
     fn call(&mut self, req: Request) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>>
     {
-        Box::pin(self.call(req))
+        let fut = tower::Service::call(self, req);
+        Box::pin(async move { fut.await.map_err(Into::into) })
     }
 }
 
@@ -77,43 +98,35 @@ trait MethodPerEndpoint {
     ) -> Result<tonic::Response<tonic::codec::Streaming<streaming_streaming::Response>>, tonic::Status>;
 }
 
+/// [`BoxService`] alias for [`ServicePerEndpoint::unary_unary`].
+pub type UnaryUnaryService = BoxService<
+    tonic::Request<unary_unary::Request>,
+    Result<tonic::Response<unary_unary::Response>, tonic::Status>,
+>;
+
+/// [`BoxService`] alias for [`ServicePerEndpoint::unary_streaming`].
+pub type UnaryStreamingService = BoxService<
+    tonic::Request<unary_streaming::Request>,
+    Result<tonic::Response<tonic::codec::Streaming<unary_streaming::Response>>, tonic::Status>,
+>;
+
+/// [`BoxService`] alias for [`ServicePerEndpoint::streaming_unary`].
+pub type StreamingUnaryService = BoxService<
+    tonic::Request<Box<dyn Stream<Item = streaming_unary::Request> + Send + Sync + 'static>>,
+    Result<tonic::Response<streaming_unary::Response>, tonic::Status>,
+>;
+
+/// [`BoxService`] alias for [`ServicePerEndpoint::streaming_streaming`].
+pub type StreamingStreamingService = BoxService<
+    tonic::Request<Box<dyn Stream<Item = streaming_streaming::Request> + Send + Sync + 'static>>,
+    Result<tonic::Response<tonic::codec::Streaming<streaming_streaming::Response>>, tonic::Status>,
+>;
+
 trait ServicePerEndpoint {
-    fn unary_unary(
-        &mut self,
-    ) -> Box<
-        dyn Service<
-            tonic::Request<unary_unary::Request>,
-            Response = Result<tonic::Response<unary_unary::Response>, tonic::Status>,
-        >,
-    >;
-    fn unary_streaming(
-        &mut self,
-    ) -> Box<
-        dyn Service<
-            tonic::Request<unary_streaming::Request>,
-            Response = Result<tonic::Response<tonic::codec::Streaming<unary_streaming::Response>>, tonic::Status>,
-        >,
-    >;
-    fn streaming_unary(
-        &mut self,
-    ) -> Box<
-        dyn Service<
-            tonic::Request<
-                Box<dyn Stream<Item = streaming_unary::Request> + Send + Sync + 'static>,
-            >,
-            Response = Result<tonic::Response<streaming_unary::Response>, tonic::Status>,
-        >,
-    >;
-    fn streaming_streaming(
-        &mut self,
-    ) -> Box<
-        dyn Service<
-            tonic::Request<
-                Box<dyn Stream<Item = streaming_streaming::Request> + Send + Sync + 'static>,
-            >,
-            Response = Result<tonic::Response<tonic::codec::Streaming<streaming_streaming::Response>>, tonic::Status>,
-        >,
-    >;
+    fn unary_unary(&mut self) -> UnaryUnaryService;
+    fn unary_streaming(&mut self) -> UnaryStreamingService;
+    fn streaming_unary(&mut self) -> StreamingUnaryService;
+    fn streaming_streaming(&mut self) -> StreamingStreamingService;
 }
 
 trait ServicePerEndpointToMethodPerEndpoint<S, M>
@@ -156,4 +169,195 @@ pub mod streaming_streaming {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use futures::stream;
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+
+    #[derive(Clone)]
+    struct Echo;
+
+    impl Service<u32> for Echo {
+        type Response = u32;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(
+            &mut self,
+            req: u32,
+        ) -> Pin<Box<dyn Future<Output = Result<u32, Error>> + Send + Sync + 'static>> {
+            Box::pin(async move { Ok(req) })
+        }
+    }
+
+    /// Resolves each call only once `notify` fires, so a test can keep a call
+    /// through a `Buffer` pending on demand.
+    #[derive(Clone)]
+    struct Gate(Arc<Notify>);
+
+    impl Service<u32> for Gate {
+        type Response = u32;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(
+            &mut self,
+            req: u32,
+        ) -> Pin<Box<dyn Future<Output = Result<u32, Error>> + Send + Sync + 'static>> {
+            let notify = self.0.clone();
+            Box::pin(async move {
+                notify.notified().await;
+                Ok(req)
+            })
+        }
+    }
+
+    fn poll_once<Req, S: Service<Req>>(svc: &mut S) -> Poll<Result<(), Error>> {
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        svc.poll_ready(&mut cx)
+    }
+
+    #[tokio::test]
+    async fn buffer_round_trips_requests() {
+        let mut buffer = Buffer::new(Echo, 4);
+        assert!(matches!(buffer.call(7).await, Ok(7)));
+    }
+
+    #[tokio::test]
+    async fn buffer_poll_ready_pending_when_full() {
+        let notify = Arc::new(Notify::new());
+        let mut buffer = Buffer::new(Gate(notify.clone()), 1);
+
+        assert!(matches!(poll_once(&mut buffer), Poll::Ready(Ok(()))));
+        let first = buffer.call(1);
+
+        // The worker task hasn't had a chance to run yet (no `.await` has
+        // happened since `call`), so the bound-1 channel is still full.
+        assert!(matches!(poll_once(&mut buffer), Poll::Pending));
+
+        notify.notify_one();
+        assert!(matches!(first.await, Ok(1)));
+    }
+
+    #[tokio::test]
+    async fn filter_allows_a_request_that_passes_the_predicate() {
+        let mut filter = Filter::new(Echo, |req: u32| async move { Ok(req) });
+        assert!(matches!(filter.call(5).await, Ok(5)));
+    }
+
+    #[tokio::test]
+    async fn filter_rejects_a_request_that_fails_the_predicate() {
+        let mut filter = Filter::new(Echo, |_req: u32| async move { Err(Error::Capacity) });
+        assert!(matches!(filter.call(5).await, Err(Error::Capacity)));
+    }
+
+    #[tokio::test]
+    async fn call_all_preserves_request_order() {
+        let results: Vec<u32> = call_all(Echo, stream::iter(vec![1u32, 2, 3]))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(results, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn call_all_unordered_yields_every_response() {
+        let mut results: Vec<u32> = call_all(Echo, stream::iter(vec![3u32, 1, 2]))
+            .unordered()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(|r| r.unwrap())
+            .collect();
+        results.sort_unstable();
+        assert_eq!(results, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn call_all_stops_after_the_first_error() {
+        struct FailSecond(u32);
+
+        impl Service<u32> for FailSecond {
+            type Response = u32;
+
+            fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn call(
+                &mut self,
+                req: u32,
+            ) -> Pin<Box<dyn Future<Output = Result<u32, Error>> + Send + Sync + 'static>> {
+                self.0 += 1;
+                if self.0 == 2 {
+                    Box::pin(async { Err(Error::Capacity) })
+                } else {
+                    Box::pin(async move { Ok(req) })
+                }
+            }
+        }
+
+        let results: Vec<_> = call_all(FailSecond(0), stream::iter(vec![1u32, 2, 3]))
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(1)));
+        assert!(matches!(results[1], Err(Error::Capacity)));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_denies_a_call_past_the_limit_even_without_poll_ready() {
+        let mut limited = RateLimit::new(Echo, 1, Duration::from_secs(60));
+        assert!(matches!(poll_once(&mut limited), Poll::Ready(Ok(()))));
+        assert!(matches!(limited.call(1).await, Ok(1)));
+
+        // No intervening `poll_ready` — the limit must still be enforced.
+        assert!(matches!(limited.call(2).await, Err(Error::Capacity)));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_poll_ready_pending_once_exhausted() {
+        let mut limited = RateLimit::new(Echo, 1, Duration::from_secs(60));
+        assert!(matches!(poll_once(&mut limited), Poll::Ready(Ok(()))));
+        let _ = limited.call(1).await;
+        assert!(matches!(poll_once(&mut limited), Poll::Pending));
+    }
+
+    #[tokio::test]
+    async fn timeout_fires_before_a_slow_inner_service_resolves() {
+        struct Delay(Duration);
+
+        impl Service<()> for Delay {
+            type Response = ();
+
+            fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn call(
+                &mut self,
+                _req: (),
+            ) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send + Sync + 'static>> {
+                let duration = self.0;
+                Box::pin(async move {
+                    tokio::time::sleep(duration).await;
+                    Ok(())
+                })
+            }
+        }
+
+        let mut svc = Timeout::new(Delay(Duration::from_millis(50)), Duration::from_millis(1));
+        assert!(matches!(svc.call(()).await, Err(Error::Timeout)));
+    }
+}
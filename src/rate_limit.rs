@@ -0,0 +1,108 @@
+use crate::{Error, Layer, Service};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::{sleep_until, Instant, Sleep};
+
+/// A [`Service`] middleware that allows at most `limit` calls per `period`,
+/// sliding into a fresh window once the previous one elapses.
+pub struct RateLimit<S> {
+    inner: S,
+    limit: u64,
+    period: Duration,
+    remaining: u64,
+    window_start: Instant,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S> RateLimit<S> {
+    /// Wrap `inner`, allowing at most `limit` calls per `period`.
+    pub fn new(inner: S, limit: u64, period: Duration) -> Self {
+        RateLimit {
+            inner,
+            limit,
+            period,
+            remaining: limit,
+            window_start: Instant::now(),
+            sleep: None,
+        }
+    }
+}
+
+impl<S, Request> Service<Request> for RateLimit<S>
+where
+    S: Service<Request> + Send + 'static,
+    S::Response: Send + Sync + 'static,
+    Request: Send + 'static,
+{
+    type Response = S::Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        let now = Instant::now();
+        if now.duration_since(self.window_start) >= self.period {
+            self.window_start = now;
+            self.remaining = self.limit;
+            self.sleep = None;
+        }
+
+        if self.remaining > 0 {
+            return self.inner.poll_ready(cx);
+        }
+
+        let deadline = self.window_start + self.period;
+        let sleep = self.sleep.get_or_insert_with(|| Box::pin(sleep_until(deadline)));
+
+        match sleep.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.window_start = Instant::now();
+                self.remaining = self.limit;
+                self.sleep = None;
+                self.inner.poll_ready(cx)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        // `call` is permitted without a preceding `poll_ready`, so re-check
+        // the limit here (starting a fresh window if the last one elapsed)
+        // rather than trusting the caller already did.
+        let now = Instant::now();
+        if now.duration_since(self.window_start) >= self.period {
+            self.window_start = now;
+            self.remaining = self.limit;
+            self.sleep = None;
+        }
+        if self.remaining == 0 {
+            return Box::pin(async { Err(Error::Capacity) });
+        }
+        self.remaining -= 1;
+        self.inner.call(req)
+    }
+}
+
+/// A [`Layer`] that produces [`RateLimit`] services.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimitLayer {
+    limit: u64,
+    period: Duration,
+}
+
+impl RateLimitLayer {
+    /// Build a layer that allows at most `limit` calls per `period`.
+    pub fn new(limit: u64, period: Duration) -> Self {
+        RateLimitLayer { limit, period }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit::new(inner, self.limit, self.period)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Error, Layer, Service};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A [`Service`] middleware that bounds how long a single `call` may take,
+/// resolving to [`Error::Timeout`] if the inner service doesn't finish in
+/// time.
+pub struct Timeout<S> {
+    inner: S,
+    duration: Duration,
+}
+
+impl<S> Timeout<S> {
+    /// Wrap `inner`, failing any call that doesn't finish within `duration`.
+    pub fn new(inner: S, duration: Duration) -> Self {
+        Timeout { inner, duration }
+    }
+}
+
+impl<S, Request> Service<Request> for Timeout<S>
+where
+    S: Service<Request> + Send + 'static,
+    S::Response: Send + Sync + 'static,
+    Request: Send + 'static,
+{
+    type Response = S::Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Response, Error>> + Send + Sync + 'static>> {
+        let fut = self.inner.call(req);
+        let duration = self.duration;
+
+        Box::pin(async move {
+            match tokio::time::timeout(duration, fut).await {
+                Ok(result) => result,
+                Err(_) => Err(Error::Timeout),
+            }
+        })
+    }
+}
+
+/// A [`Layer`] that produces [`Timeout`] services.
+#[derive(Clone, Copy, Debug)]
+pub struct TimeoutLayer {
+    duration: Duration,
+}
+
+impl TimeoutLayer {
+    /// Build a layer that bounds calls to `duration`.
+    pub fn new(duration: Duration) -> Self {
+        TimeoutLayer { duration }
+    }
+}
+
+impl<S> Layer<S> for TimeoutLayer {
+    type Service = Timeout<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Timeout::new(inner, self.duration)
+    }
+}